@@ -0,0 +1,24 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    if std::env::var_os("PROTOC").is_none() {
+        std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    }
+
+    let mut config = tonic_build::configure().build_client(true).build_server(true);
+
+    if std::env::var_os("CARGO_FEATURE_SERDE").is_some() {
+        // DecimalValue keeps a hand-written Serialize/Deserialize (see
+        // `serde_support`) instead of the derive below, so it round-trips
+        // as a human-readable decimal string rather than raw mantissa/scale
+        // integers.
+        for path in [
+            "crosslink.SubscribeRequest",
+            "crosslink.PriceUpdate",
+            "crosslink.GetLatestRequest",
+        ] {
+            config = config.type_attribute(path, "#[derive(serde::Serialize, serde::Deserialize)]");
+        }
+    }
+
+    config.compile(&["proto/crosslink.proto"], &["proto"])?;
+    Ok(())
+}
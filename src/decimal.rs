@@ -0,0 +1,167 @@
+//! Conversions between the wire [`crosslink::DecimalValue`](crate::crosslink::DecimalValue)
+//! and [`rust_decimal::Decimal`].
+//!
+//! `DecimalValue::value` is treated as the signed mantissa and
+//! `DecimalValue::decimal` as the scale (number of fractional digits), which
+//! is exactly the shape `Decimal::from_i128_with_scale` expects.
+
+use std::fmt;
+
+use rust_decimal::Decimal;
+
+use crate::crosslink::DecimalValue;
+
+/// Largest scale `rust_decimal::Decimal` can represent.
+pub(crate) const MAX_SCALE: u32 = 28;
+
+/// Error produced when converting between [`DecimalValue`] and
+/// [`rust_decimal::Decimal`] would lose information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalConversionError {
+    /// `DecimalValue::decimal` is outside the 0..=28 range `Decimal` supports.
+    ScaleOutOfRange(i32),
+    /// `Decimal::mantissa()` does not fit in `DecimalValue::value` (`i64`).
+    MantissaOutOfRange(i128),
+}
+
+impl fmt::Display for DecimalConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecimalConversionError::ScaleOutOfRange(scale) => write!(
+                f,
+                "decimal scale {scale} is out of range for rust_decimal::Decimal (0..={MAX_SCALE})"
+            ),
+            DecimalConversionError::MantissaOutOfRange(mantissa) => write!(
+                f,
+                "mantissa {mantissa} does not fit in DecimalValue::value (i64)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DecimalConversionError {}
+
+impl TryFrom<DecimalValue> for Decimal {
+    type Error = DecimalConversionError;
+
+    fn try_from(value: DecimalValue) -> Result<Self, Self::Error> {
+        if value.decimal < 0 || value.decimal as u32 > MAX_SCALE {
+            return Err(DecimalConversionError::ScaleOutOfRange(value.decimal));
+        }
+        Ok(Decimal::from_i128_with_scale(
+            value.value as i128,
+            value.decimal as u32,
+        ))
+    }
+}
+
+impl TryFrom<Decimal> for DecimalValue {
+    type Error = DecimalConversionError;
+
+    fn try_from(decimal: Decimal) -> Result<Self, Self::Error> {
+        let mantissa = decimal.mantissa();
+        let value = i64::try_from(mantissa)
+            .map_err(|_| DecimalConversionError::MantissaOutOfRange(mantissa))?;
+        Ok(DecimalValue {
+            value,
+            decimal: decimal.scale() as i32,
+        })
+    }
+}
+
+impl DecimalValue {
+    /// Adds two `DecimalValue`s by normalizing both to a common
+    /// `rust_decimal::Decimal` scale first.
+    pub fn checked_add(&self, other: &DecimalValue) -> Result<DecimalValue, DecimalConversionError> {
+        let lhs = Decimal::try_from(self.clone())?;
+        let rhs = Decimal::try_from(other.clone())?;
+        DecimalValue::try_from(lhs + rhs)
+    }
+
+    /// Subtracts `other` from `self` by normalizing both to a common
+    /// `rust_decimal::Decimal` scale first.
+    pub fn checked_sub(&self, other: &DecimalValue) -> Result<DecimalValue, DecimalConversionError> {
+        let lhs = Decimal::try_from(self.clone())?;
+        let rhs = Decimal::try_from(other.clone())?;
+        DecimalValue::try_from(lhs - rhs)
+    }
+
+    /// Compares two `DecimalValue`s after normalizing both to a common
+    /// `rust_decimal::Decimal` scale. Returns `None` if either side cannot
+    /// be represented as a `Decimal`.
+    ///
+    /// Deliberately not a `PartialOrd` impl: normalized comparison considers
+    /// `{value: 1, decimal: 0}` and `{value: 100, decimal: 2}` equal, while
+    /// `DecimalValue`'s derived (structural) `PartialEq` does not. Exposing
+    /// that as `PartialOrd` would violate the stdlib's consistency contract
+    /// between the two traits.
+    pub fn cmp_normalized(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let lhs = Decimal::try_from(self.clone()).ok()?;
+        let rhs = Decimal::try_from(other.clone()).ok()?;
+        lhs.partial_cmp(&rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_decimal() {
+        let value = DecimalValue {
+            value: 12345,
+            decimal: 2,
+        };
+        let decimal = Decimal::try_from(value.clone()).unwrap();
+        assert_eq!(decimal.to_string(), "123.45");
+        assert_eq!(DecimalValue::try_from(decimal).unwrap(), value);
+    }
+
+    #[test]
+    fn rejects_scale_out_of_range() {
+        let value = DecimalValue {
+            value: 1,
+            decimal: 29,
+        };
+        assert_eq!(
+            Decimal::try_from(value).unwrap_err(),
+            DecimalConversionError::ScaleOutOfRange(29)
+        );
+    }
+
+    #[test]
+    fn rejects_mantissa_overflow() {
+        let decimal = Decimal::from_i128_with_scale(i64::MAX as i128 + 1, 0);
+        assert!(matches!(
+            DecimalValue::try_from(decimal),
+            Err(DecimalConversionError::MantissaOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn compares_across_scales() {
+        let a = DecimalValue {
+            value: 1,
+            decimal: 0,
+        };
+        let b = DecimalValue {
+            value: 100,
+            decimal: 2,
+        };
+        assert_eq!(a.cmp_normalized(&b), Some(std::cmp::Ordering::Equal));
+    }
+
+    #[test]
+    fn checked_add_normalizes_scale() {
+        let a = DecimalValue {
+            value: 1,
+            decimal: 0,
+        };
+        let b = DecimalValue {
+            value: 50,
+            decimal: 2,
+        };
+        let sum = a.checked_add(&b).unwrap();
+        assert_eq!(Decimal::try_from(sum).unwrap().to_string(), "1.50");
+    }
+}
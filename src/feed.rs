@@ -0,0 +1,279 @@
+//! A small polling feed client, modeled after the reflector pattern used by
+//! Chainlink's `rustlink` crate: a background task repeatedly polls a set of
+//! feed identifiers and forwards decoded [`crosslink::DecimalValue`](crate::crosslink::DecimalValue)
+//! updates to the caller over a channel.
+
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use futures::channel::{mpsc, oneshot};
+use futures::{select_biased, FutureExt, SinkExt};
+use tokio::time::MissedTickBehavior;
+
+use crate::crosslink::DecimalValue;
+
+/// One update emitted by a running [`Feed`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedUpdate {
+    pub feed_id: String,
+    pub value: DecimalValue,
+    pub round_id: u64,
+    pub observed_at: SystemTime,
+}
+
+/// Which feed identifiers to poll, and how often.
+#[derive(Debug, Clone)]
+pub struct FeedConfig {
+    pub feed_ids: Vec<String>,
+    pub poll_interval: Duration,
+}
+
+/// Source of round data for a feed id. Kept separate from [`Feed`] so the
+/// actual transport (HTTP, an on-chain RPC client, a test double, ...) can
+/// be swapped without touching the polling/shutdown plumbing.
+#[async_trait::async_trait]
+pub trait FeedSource: Send + Sync + 'static {
+    async fn latest_round(&self, feed_id: &str) -> Result<(u64, DecimalValue), FeedError>;
+}
+
+#[derive(Debug)]
+pub enum FeedError {
+    /// The underlying source failed to produce a round for a feed id.
+    Source(String),
+    /// The receiving end of the update channel was dropped.
+    Disconnected,
+}
+
+impl fmt::Display for FeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FeedError::Source(msg) => write!(f, "feed source error: {msg}"),
+            FeedError::Disconnected => write!(f, "feed update receiver was dropped"),
+        }
+    }
+}
+
+impl std::error::Error for FeedError {}
+
+/// Where a [`Feed`] forwards updates: either a channel the caller already
+/// owns, or one the caller wants `Feed` to create and hand back to them.
+pub enum FeedSink {
+    Bounded(mpsc::Sender<FeedUpdate>),
+    Unbounded(mpsc::UnboundedSender<FeedUpdate>),
+}
+
+impl From<mpsc::Sender<FeedUpdate>> for FeedSink {
+    fn from(sender: mpsc::Sender<FeedUpdate>) -> Self {
+        FeedSink::Bounded(sender)
+    }
+}
+
+impl From<mpsc::UnboundedSender<FeedUpdate>> for FeedSink {
+    fn from(sender: mpsc::UnboundedSender<FeedUpdate>) -> Self {
+        FeedSink::Unbounded(sender)
+    }
+}
+
+impl FeedSink {
+    async fn send(&mut self, update: FeedUpdate) -> Result<(), FeedError> {
+        match self {
+            FeedSink::Bounded(tx) => tx.send(update).await.map_err(|_| FeedError::Disconnected),
+            FeedSink::Unbounded(tx) => tx.unbounded_send(update).map_err(|_| FeedError::Disconnected),
+        }
+    }
+}
+
+/// Handle to a running feed subscription.
+///
+/// Dropping the handle directly aborts the background task immediately,
+/// possibly mid-fetch. Call [`Feed::shutdown`] instead for a graceful stop
+/// that lets the task finish whatever poll iteration it's currently in.
+pub struct Feed {
+    shutdown: Option<oneshot::Sender<()>>,
+    task: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl Feed {
+    /// Spawns a background task that polls `source` for every id in
+    /// `config.feed_ids` every `config.poll_interval`, forwarding each
+    /// update to `sink`.
+    pub fn try_new<S: FeedSource>(
+        config: FeedConfig,
+        source: S,
+        sink: impl Into<FeedSink>,
+    ) -> Result<Feed, FeedError> {
+        let mut sink = sink.into();
+        let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
+
+        let task = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(config.poll_interval);
+            // A slow fetch shouldn't cause a burst of back-to-back catch-up
+            // ticks once it finally returns.
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                // `select_biased!` (rather than `select!`) always checks
+                // `shutdown_rx` first, so a shutdown requested while a fetch
+                // was in flight is honored as soon as that fetch's iteration
+                // finishes, instead of racing against the next tick.
+                select_biased! {
+                    _ = &mut shutdown_rx => return,
+                    _ = ticker.tick().fuse() => {
+                        for feed_id in &config.feed_ids {
+                            let (round_id, value) = match source.latest_round(feed_id).await {
+                                Ok(round) => round,
+                                Err(_) => continue,
+                            };
+                            let update = FeedUpdate {
+                                feed_id: feed_id.clone(),
+                                value,
+                                round_id,
+                                observed_at: SystemTime::now(),
+                            };
+                            if sink.send(update).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(Feed {
+            shutdown: Some(shutdown_tx),
+            task: Some(task),
+        })
+    }
+
+    /// Convenience constructor for callers who don't already have a
+    /// channel: creates a bounded channel of `buffer` updates and returns
+    /// the receiving end alongside the running feed.
+    pub fn try_new_with_receiver<S: FeedSource>(
+        config: FeedConfig,
+        source: S,
+        buffer: usize,
+    ) -> Result<(Feed, mpsc::Receiver<FeedUpdate>), FeedError> {
+        let (tx, rx) = mpsc::channel(buffer);
+        let feed = Feed::try_new(config, source, tx)?;
+        Ok((feed, rx))
+    }
+
+    /// Signals the background task to stop and waits for it to exit.
+    ///
+    /// Unlike dropping the `Feed` (which aborts the task immediately), this
+    /// lets an in-flight `latest_round` fetch and send finish before the
+    /// task observes the shutdown signal and returns on its own.
+    pub async fn shutdown(mut self) {
+        if let Some(tx) = self.shutdown.take() {
+            let _ = tx.send(());
+        }
+        if let Some(task) = self.task.take() {
+            let _ = task.await;
+        }
+    }
+}
+
+impl Drop for Feed {
+    fn drop(&mut self) {
+        // `shutdown` already took `self.task` and waited for it to exit on
+        // its own; this only runs for handles dropped without going
+        // through `shutdown`, where aborting is the only option left.
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use futures::StreamExt;
+
+    use super::*;
+
+    struct FixedSource;
+
+    #[async_trait::async_trait]
+    impl FeedSource for FixedSource {
+        async fn latest_round(&self, _feed_id: &str) -> Result<(u64, DecimalValue), FeedError> {
+            Ok((1, DecimalValue { value: 42, decimal: 0 }))
+        }
+    }
+
+    /// A source whose `latest_round` takes `delay` to resolve and records
+    /// how many times it actually ran to completion.
+    struct SlowSource {
+        delay: Duration,
+        completed: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl FeedSource for SlowSource {
+        async fn latest_round(&self, _feed_id: &str) -> Result<(u64, DecimalValue), FeedError> {
+            tokio::time::sleep(self.delay).await;
+            self.completed.fetch_add(1, Ordering::SeqCst);
+            Ok((1, DecimalValue { value: 1, decimal: 0 }))
+        }
+    }
+
+    fn config(poll_interval_ms: u64) -> FeedConfig {
+        FeedConfig {
+            feed_ids: vec!["eth-usd".to_string()],
+            poll_interval: Duration::from_millis(poll_interval_ms),
+        }
+    }
+
+    #[tokio::test]
+    async fn delivers_updates_to_an_owned_receiver() {
+        let (feed, mut rx) = Feed::try_new_with_receiver(config(5), FixedSource, 8).unwrap();
+
+        let update = tokio::time::timeout(Duration::from_millis(200), rx.next())
+            .await
+            .expect("update arrived before timeout")
+            .expect("sender not dropped");
+
+        assert_eq!(update.feed_id, "eth-usd");
+        assert_eq!(
+            update.value,
+            DecimalValue {
+                value: 42,
+                decimal: 0
+            }
+        );
+
+        feed.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn shutdown_lets_an_in_flight_poll_finish() {
+        let completed = Arc::new(AtomicUsize::new(0));
+        let source = SlowSource {
+            delay: Duration::from_millis(50),
+            completed: completed.clone(),
+        };
+        let (feed, _rx) = Feed::try_new_with_receiver(config(10), source, 8).unwrap();
+
+        // Let the ticker fire and the 50ms fetch begin before asking to stop.
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        feed.shutdown().await;
+
+        assert_eq!(completed.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn dropping_the_handle_aborts_an_in_flight_poll() {
+        let completed = Arc::new(AtomicUsize::new(0));
+        let source = SlowSource {
+            delay: Duration::from_millis(50),
+            completed: completed.clone(),
+        };
+        let (feed, _rx) = Feed::try_new_with_receiver(config(10), source, 8).unwrap();
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        drop(feed);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(completed.load(Ordering::SeqCst), 0);
+    }
+}
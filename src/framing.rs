@@ -0,0 +1,184 @@
+//! Length-delimited framing for `crosslink` messages on top of plain
+//! [`std::io::Read`]/[`std::io::Write`] streams (files, sockets, ...).
+//!
+//! Each frame is a protobuf varint length prefix followed by that many
+//! bytes of encoded message, matching [`prost::Message::encode_length_delimited`]
+//! / [`prost::Message::decode_length_delimited`].
+
+use std::io::{self, Read, Write};
+use std::marker::PhantomData;
+
+use prost::{DecodeError, Message};
+
+/// Writes a stream of messages, each framed with a varint length prefix.
+pub struct CrosslinkWriter<W> {
+    inner: W,
+    buf: Vec<u8>,
+}
+
+impl<W: Write> CrosslinkWriter<W> {
+    pub fn new(inner: W) -> Self {
+        CrosslinkWriter {
+            inner,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Encodes `message` and writes it as a single length-delimited frame.
+    pub fn write_message<T: Message>(&mut self, message: &T) -> io::Result<()> {
+        self.buf.clear();
+        message
+            .encode_length_delimited(&mut self.buf)
+            .expect("Vec<u8> provides sufficient capacity");
+        self.inner.write_all(&self.buf)
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Reads a stream of length-delimited messages of type `T`, one frame at a
+/// time. Yields `Ok(None)`-style termination via `Iterator::next` returning
+/// `None` only on a clean boundary (no bytes read before EOF); a frame that
+/// is truncated mid-length-prefix or mid-payload yields `Some(Err(_))`.
+pub struct CrosslinkReader<R, T> {
+    inner: R,
+    _marker: PhantomData<T>,
+}
+
+impl<R: Read, T: Message + Default> CrosslinkReader<R, T> {
+    pub fn new(inner: R) -> Self {
+        CrosslinkReader {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads a protobuf varint length prefix. Returns `Ok(None)` only if EOF
+    /// is hit before any byte of the prefix is read.
+    fn read_length_prefix(&mut self) -> io::Result<Option<u64>> {
+        let mut value: u64 = 0;
+        let mut shift: u32 = 0;
+        let mut byte = [0u8; 1];
+        loop {
+            match self.inner.read(&mut byte)? {
+                0 if shift == 0 => return Ok(None),
+                0 => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "stream ended mid length prefix",
+                    ))
+                }
+                _ => {}
+            }
+            if shift >= 64 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "length prefix varint is too long (more than 10 bytes)",
+                ));
+            }
+            value |= u64::from(byte[0] & 0x7f) << shift;
+            if byte[0] & 0x80 == 0 {
+                return Ok(Some(value));
+            }
+            shift += 7;
+        }
+    }
+}
+
+impl<R: Read, T: Message + Default> Iterator for CrosslinkReader<R, T> {
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = match self.read_length_prefix() {
+            Ok(Some(len)) => len as usize,
+            Ok(None) => return None,
+            Err(err) => return Some(Err(err)),
+        };
+
+        let mut payload = vec![0u8; len];
+        if let Err(err) = self.inner.read_exact(&mut payload) {
+            return Some(Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("stream ended mid message payload: {err}"),
+            )));
+        }
+
+        match T::decode(payload.as_slice()) {
+            Ok(message) => Some(Ok(message)),
+            Err(err) => Some(Err(io::Error::new(io::ErrorKind::InvalidData, DecodeErrorWrap(err)))),
+        }
+    }
+}
+
+/// Wraps [`DecodeError`] so it can be carried inside an [`io::Error`].
+#[derive(Debug)]
+struct DecodeErrorWrap(DecodeError);
+
+impl std::fmt::Display for DecodeErrorWrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for DecodeErrorWrap {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crosslink::DecimalValue;
+
+    fn sample(value: i64, decimal: i32) -> DecimalValue {
+        DecimalValue { value, decimal }
+    }
+
+    #[test]
+    fn round_trips_multiple_messages() {
+        let mut buf = Vec::new();
+        {
+            let mut writer = CrosslinkWriter::new(&mut buf);
+            writer.write_message(&sample(1, 2)).unwrap();
+            writer.write_message(&sample(3, 4)).unwrap();
+        }
+
+        let reader: CrosslinkReader<_, DecimalValue> = CrosslinkReader::new(buf.as_slice());
+        let messages: Vec<_> = reader.collect::<io::Result<Vec<_>>>().unwrap();
+        assert_eq!(messages, vec![sample(1, 2), sample(3, 4)]);
+    }
+
+    #[test]
+    fn clean_eof_yields_none() {
+        let mut reader: CrosslinkReader<_, DecimalValue> = CrosslinkReader::new(&[][..]);
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn truncated_frame_is_an_error() {
+        let mut buf = Vec::new();
+        CrosslinkWriter::new(&mut buf)
+            .write_message(&sample(42, 0))
+            .unwrap();
+        buf.truncate(buf.len() - 1);
+
+        let mut reader: CrosslinkReader<_, DecimalValue> = CrosslinkReader::new(buf.as_slice());
+        assert!(reader.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn overlong_length_prefix_is_an_error_not_a_panic() {
+        // 11 continuation bytes: no varint this long is valid, and the
+        // reader must reject it instead of overflowing the shift.
+        let buf = vec![0xffu8; 11];
+        let mut reader: CrosslinkReader<_, DecimalValue> = CrosslinkReader::new(buf.as_slice());
+        assert!(reader.next().unwrap().is_err());
+    }
+}
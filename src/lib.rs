@@ -2,6 +2,23 @@ pub mod crosslink {
     tonic::include_proto!("crosslink");
 }
 
+mod decimal;
+pub use decimal::DecimalConversionError;
+
+pub mod framing;
+
+pub mod feed;
+
+/// Re-exports of the generated gRPC client/server so consumers don't need
+/// to reach into [`crosslink`]'s generated submodules directly.
+pub mod grpc {
+    pub use crate::crosslink::crosslink_client::CrosslinkClient;
+    pub use crate::crosslink::crosslink_server::{Crosslink, CrosslinkServer};
+}
+
+#[cfg(feature = "serde")]
+mod serde_support;
+
 #[cfg(test)]
 mod tests {
     use super::crosslink;
@@ -13,8 +30,7 @@ mod tests {
             value: 1,
             decimal: 2,
         };
-        let mut encoded = Vec::new();
-        encoded.reserve(decimal.encoded_len());
+        let mut encoded = Vec::with_capacity(decimal.encoded_len());
         decimal.encode(&mut encoded).unwrap();
         let decoded = crosslink::DecimalValue::decode(std::io::Cursor::new(encoded)).unwrap();
 
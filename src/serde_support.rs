@@ -0,0 +1,180 @@
+//! Hand-written serde support for `crosslink` messages, enabled by the
+//! `serde` feature.
+//!
+//! prost deliberately has no reflection, so `Serialize`/`Deserialize` for
+//! the other generated messages are injected via `type_attribute` in
+//! `build.rs`. `DecimalValue` is handled here instead: rather than exposing
+//! its raw `value`/`decimal` wire fields, it (de)serializes as a
+//! human-readable decimal string, e.g. `"123.45"`.
+
+use std::fmt;
+
+use serde::de::{self, Visitor};
+use serde::ser;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::crosslink::DecimalValue;
+use crate::decimal::{DecimalConversionError, MAX_SCALE};
+
+impl Serialize for DecimalValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let text = decimal_string(self).map_err(ser::Error::custom)?;
+        serializer.serialize_str(&text)
+    }
+}
+
+impl<'de> Deserialize<'de> for DecimalValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(DecimalValueVisitor)
+    }
+}
+
+/// Renders `value * 10^-decimal` as a plain decimal string, without pulling
+/// in `rust_decimal` (see [`crate::decimal`]) just for formatting.
+///
+/// Rejects the same out-of-range scales that
+/// `TryFrom<DecimalValue> for Decimal` does (0..=28), rather than silently
+/// clamping a negative `decimal` to zero: a negative `decimal` is a
+/// legitimate wire value (it means "multiply by 10^|decimal|"), and
+/// clamping it would silently drop magnitude instead of reporting it.
+fn decimal_string(value: &DecimalValue) -> Result<String, DecimalConversionError> {
+    if value.decimal < 0 || value.decimal as u32 > MAX_SCALE {
+        return Err(DecimalConversionError::ScaleOutOfRange(value.decimal));
+    }
+
+    let negative = value.value < 0;
+    let digits = value.value.unsigned_abs().to_string();
+    let scale = value.decimal as usize;
+
+    let mut out = String::new();
+    if negative {
+        out.push('-');
+    }
+
+    if scale == 0 {
+        out.push_str(&digits);
+        return Ok(out);
+    }
+
+    if digits.len() <= scale {
+        out.push_str("0.");
+        out.push_str(&"0".repeat(scale - digits.len()));
+        out.push_str(&digits);
+    } else {
+        let split = digits.len() - scale;
+        out.push_str(&digits[..split]);
+        out.push('.');
+        out.push_str(&digits[split..]);
+    }
+    Ok(out)
+}
+
+struct DecimalValueVisitor;
+
+impl<'de> Visitor<'de> for DecimalValueVisitor {
+    type Value = DecimalValue;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a decimal string such as \"123.45\"")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        parse_decimal_string(s).ok_or_else(|| de::Error::custom(format!("invalid decimal string: {s}")))
+    }
+}
+
+fn parse_decimal_string(s: &str) -> Option<DecimalValue> {
+    let (sign, rest) = match s.strip_prefix('-') {
+        Some(rest) => (-1i64, rest),
+        None => (1i64, s),
+    };
+
+    let (int_part, frac_part) = match rest.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, frac_part),
+        None => (rest, ""),
+    };
+
+    if int_part.is_empty() && frac_part.is_empty() {
+        return None;
+    }
+    if !int_part.chars().all(|c| c.is_ascii_digit()) || !frac_part.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let mantissa_digits = format!("{int_part}{frac_part}");
+    let magnitude: i64 = if mantissa_digits.is_empty() {
+        0
+    } else {
+        mantissa_digits.parse().ok()?
+    };
+
+    Some(DecimalValue {
+        value: sign * magnitude,
+        decimal: frac_part.len() as i32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(value: i64, decimal: i32) {
+        let original = DecimalValue { value, decimal };
+        let json = serde_json::to_string(&original).unwrap();
+        let decoded: DecimalValue = serde_json::from_str(&json).unwrap();
+        assert_eq!(original, decoded, "round trip through {json}");
+    }
+
+    #[test]
+    fn formats_as_decimal_string() {
+        assert_eq!(
+            serde_json::to_string(&DecimalValue {
+                value: 12345,
+                decimal: 2
+            })
+            .unwrap(),
+            "\"123.45\""
+        );
+        assert_eq!(
+            serde_json::to_string(&DecimalValue {
+                value: -5,
+                decimal: 3
+            })
+            .unwrap(),
+            "\"-0.005\""
+        );
+        assert_eq!(
+            serde_json::to_string(&DecimalValue { value: 7, decimal: 0 }).unwrap(),
+            "\"7\""
+        );
+    }
+
+    #[test]
+    fn round_trips_various_values() {
+        round_trip(12345, 2);
+        round_trip(-5, 3);
+        round_trip(0, 0);
+        round_trip(7, 0);
+        round_trip(100, 5);
+    }
+
+    #[test]
+    fn rejects_negative_scale_instead_of_silently_clamping() {
+        let err = serde_json::to_string(&DecimalValue {
+            value: 12345,
+            decimal: -2,
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("out of range"));
+    }
+
+    #[test]
+    fn rejects_scale_above_rust_decimal_maximum() {
+        let err = serde_json::to_string(&DecimalValue {
+            value: 1,
+            decimal: 29,
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("out of range"));
+    }
+}
@@ -0,0 +1,117 @@
+//! Spins up the generated `Crosslink` service in-process and round-trips a
+//! `DecimalValue` over both the unary and streaming RPCs.
+
+use std::net::SocketAddr;
+
+use futures::StreamExt;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+
+use crosslink_protocol::crosslink::{DecimalValue, GetLatestRequest, PriceUpdate, SubscribeRequest};
+use crosslink_protocol::grpc::{Crosslink, CrosslinkClient, CrosslinkServer};
+
+struct TestService;
+
+#[tonic::async_trait]
+impl Crosslink for TestService {
+    type SubscribePricesStream =
+        std::pin::Pin<Box<dyn futures::Stream<Item = Result<PriceUpdate, Status>> + Send + 'static>>;
+
+    async fn subscribe_prices(
+        &self,
+        _request: Request<SubscribeRequest>,
+    ) -> Result<Response<Self::SubscribePricesStream>, Status> {
+        let update = PriceUpdate {
+            feed_id: "eth-usd".into(),
+            value: Some(DecimalValue {
+                value: 12_345,
+                decimal: 2,
+            }),
+            round_id: 1,
+        };
+        let stream = futures::stream::iter(vec![Ok(update)]);
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    async fn get_latest(
+        &self,
+        request: Request<GetLatestRequest>,
+    ) -> Result<Response<PriceUpdate>, Status> {
+        Ok(Response::new(PriceUpdate {
+            feed_id: request.into_inner().feed_id,
+            value: Some(DecimalValue {
+                value: 42,
+                decimal: 0,
+            }),
+            round_id: 7,
+        }))
+    }
+}
+
+async fn spawn_server() -> (SocketAddr, oneshot::Sender<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (shutdown_tx, shutdown_rx) = oneshot::channel();
+
+    tokio::spawn(async move {
+        Server::builder()
+            .add_service(CrosslinkServer::new(TestService))
+            .serve_with_incoming_shutdown(TcpListenerStream::new(listener), async {
+                shutdown_rx.await.ok();
+            })
+            .await
+            .unwrap();
+    });
+
+    (addr, shutdown_tx)
+}
+
+#[tokio::test]
+async fn round_trips_get_latest() {
+    let (addr, _shutdown) = spawn_server().await;
+    let mut client = CrosslinkClient::connect(format!("http://{addr}")).await.unwrap();
+
+    let response = client
+        .get_latest(GetLatestRequest {
+            feed_id: "eth-usd".into(),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.feed_id, "eth-usd");
+    assert_eq!(
+        response.value,
+        Some(DecimalValue {
+            value: 42,
+            decimal: 0,
+        })
+    );
+}
+
+#[tokio::test]
+async fn round_trips_subscribe_prices() {
+    let (addr, _shutdown) = spawn_server().await;
+    let mut client = CrosslinkClient::connect(format!("http://{addr}")).await.unwrap();
+
+    let mut stream = client
+        .subscribe_prices(SubscribeRequest {
+            feed_ids: vec!["eth-usd".into()],
+        })
+        .await
+        .unwrap()
+        .into_inner();
+
+    let update = stream.next().await.unwrap().unwrap();
+    assert_eq!(update.feed_id, "eth-usd");
+    assert_eq!(
+        update.value,
+        Some(DecimalValue {
+            value: 12_345,
+            decimal: 2,
+        })
+    );
+}